@@ -0,0 +1,136 @@
+use crate::error::Error;
+use crate::tree::CollapsibleItem;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A request saved under a collection folder, with enough state to replay it
+/// straight into the execution panes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedRequest {
+    pub url: String,
+    pub query: String,
+    pub variables: String,
+    pub headers: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TreeNode {
+    Folder { name: String },
+    Request { name: String, request: SavedRequest },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TreeItem {
+    pub node: TreeNode,
+    pub indent: u8,
+    pub collapsed: bool,
+    #[serde(default = "default_true")]
+    pub visible: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl CollapsibleItem for TreeItem {
+    fn indent(&self) -> u8 {
+        self.indent
+    }
+
+    fn collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed = collapsed;
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+}
+
+/// A flat, pre-order listing of folders and requests. Collapsing a folder
+/// flips `visible` on every item nested below it, so rendering is just a
+/// filter over `visible` rather than a recursive tree walk.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Collection {
+    pub items: Vec<TreeItem>,
+}
+
+impl Collection {
+    pub fn config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("graffi-tui").join("collections.yaml"))
+    }
+
+    pub fn load() -> Collection {
+        Self::config_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_yaml::from_str(&contents).ok())
+            .unwrap_or_else(Collection::sample)
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let path = Self::config_path().ok_or_else(|| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no config dir",
+            ))
+        })?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let yaml = serde_yaml::to_string(self)?;
+
+        fs::write(path, yaml)?;
+
+        Ok(())
+    }
+
+    pub fn visible_indices(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.visible)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Toggles the folder at `index`. See `tree::toggle_collapse` for the
+    /// cascade rules.
+    pub fn toggle_collapse(&mut self, index: usize) {
+        crate::tree::toggle_collapse(&mut self.items, index);
+    }
+
+    fn sample() -> Collection {
+        Collection {
+            items: vec![
+                TreeItem {
+                    node: TreeNode::Folder {
+                        name: "Rick and Morty API".to_string(),
+                    },
+                    indent: 0,
+                    collapsed: false,
+                    visible: true,
+                },
+                TreeItem {
+                    node: TreeNode::Request {
+                        name: "character".to_string(),
+                        request: SavedRequest {
+                            url: "https://rickandmortyapi.com/graphql".to_string(),
+                            query: "query character { id, name, status }".to_string(),
+                            variables: "{}".to_string(),
+                            headers: Vec::new(),
+                        },
+                    },
+                    indent: 1,
+                    collapsed: false,
+                    visible: true,
+                },
+            ],
+        }
+    }
+}
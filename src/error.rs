@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("request failed: {0}")]
+    Reqwest(#[from] reqwest::Error),
+
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid GraphQL query: {0}")]
+    Parse(String),
+
+    #[error("invalid header: {0}")]
+    Header(String),
+
+    #[error("could not send event to the render loop")]
+    ChannelSend,
+
+    #[error("file error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("invalid collection file: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("history database error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+}
@@ -1,45 +1,101 @@
-use reqwest::header::{HeaderMap, ACCEPT, CONTENT_TYPE};
+use crate::error::Error;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 use serde::{Deserialize, Serialize};
-use std::error::Error;
 
-const API_URL: &str = "https://rickandmortyapi.com/graphql";
-const QUERY: &str = "{\"operationName\":null,\"variables\":{},\"query\":\"{  character(id: 1) { id name status }}\"}";
+#[derive(Serialize)]
+struct GraphQLRequestBody<'a> {
+    #[serde(rename = "operationName")]
+    operation_name: Option<&'a str>,
+    variables: serde_json::Value,
+    query: &'a str,
+}
+
+/// Everything needed to reach a GraphQL endpoint: the target instance URL,
+/// an optional bearer token, and any custom headers the user added.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestContext {
+    pub url: String,
+    pub auth_token: Option<String>,
+    pub headers: Vec<(String, String)>,
+}
+
+impl RequestContext {
+    pub fn auth(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+
+        self
+    }
+
+    fn header_map(&self) -> Result<HeaderMap, Error> {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
-#[derive(Serialize, Deserialize, Debug)]
+        if let Some(token) = &self.auth_token {
+            let value = HeaderValue::from_str(&format!("Bearer {}", token))
+                .map_err(|error| Error::Header(error.to_string()))?;
+
+            headers.insert(AUTHORIZATION, value);
+        }
+
+        for (name, value) in &self.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|error| Error::Header(error.to_string()))?;
+            let header_value =
+                HeaderValue::from_str(value).map_err(|error| Error::Header(error.to_string()))?;
+
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(headers)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Character {
     id: String,
     name: String,
     status: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CharacterDataField {
     character: Character,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct GraphQLResponse<T> {
     data: T,
 }
 
-pub async fn perform_graphql() -> Result<GraphQLResponse<CharacterDataField>, Box<dyn Error>> {
-    let mut headers = HeaderMap::new();
+pub async fn perform_graphql(
+    context: &RequestContext,
+    query: &str,
+    variables: serde_json::Value,
+    operation_name: Option<&str>,
+) -> Result<(u16, GraphQLResponse<CharacterDataField>), Error> {
+    let headers = context.header_map()?;
 
-    headers.insert(ACCEPT, "application/json".parse().unwrap());
-    headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+    let body = GraphQLRequestBody {
+        operation_name,
+        variables,
+        query,
+    };
 
     let client = reqwest::Client::new();
 
     let response = client
-        .post(API_URL)
+        .post(&context.url)
         .headers(headers)
-        .body(QUERY)
+        .json(&body)
         .send()
-        .await?
-        .text()
         .await?;
 
-    let json_response: GraphQLResponse<CharacterDataField> = serde_json::from_str(&response)?;
+    let status = response.status().as_u16();
+    let text = response.text().await?;
+
+    let json_response: GraphQLResponse<CharacterDataField> = serde_json::from_str(&text)?;
 
-    Ok(json_response)
+    Ok((status, json_response))
 }
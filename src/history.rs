@@ -0,0 +1,114 @@
+use crate::error::Error;
+use rusqlite::{params, Connection};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One executed request, recorded so it can be recalled and re-run later.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub timestamp: i64,
+    pub url: String,
+    pub operation_name: Option<String>,
+    pub query: String,
+    pub variables: String,
+    pub headers: String,
+    pub status: String,
+    pub latency_ms: i64,
+}
+
+pub fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+fn db_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("graffi-tui").join("history.sqlite3"))
+}
+
+/// Opens the history database and ensures its schema exists. Called once at
+/// startup; the returned connection is shared by every later `record`/
+/// `load_recent` call instead of each reopening the database.
+pub fn open_connection() -> Result<Connection, Error> {
+    let connection = match db_path() {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            Connection::open(path)?
+        }
+        None => Connection::open_in_memory()?,
+    };
+
+    connection.execute(
+        "CREATE TABLE IF NOT EXISTS history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            url TEXT NOT NULL,
+            operation_name TEXT,
+            query TEXT NOT NULL,
+            variables TEXT NOT NULL,
+            headers TEXT NOT NULL DEFAULT '',
+            status TEXT NOT NULL,
+            latency_ms INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    // Older databases were created before `headers` existed; add it rather
+    // than losing their history. Ignore the error when the column is
+    // already there (either from the `CREATE TABLE` above or a previous run
+    // of this migration).
+    let _ = connection.execute(
+        "ALTER TABLE history ADD COLUMN headers TEXT NOT NULL DEFAULT ''",
+        [],
+    );
+
+    Ok(connection)
+}
+
+/// Persists one request/response pair. Called from the same background
+/// worker that performs the HTTP call, so this never runs on the render
+/// thread.
+pub fn record(connection: &Connection, entry: &HistoryEntry) -> Result<(), Error> {
+    connection.execute(
+        "INSERT INTO history (timestamp, url, operation_name, query, variables, headers, status, latency_ms)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            entry.timestamp,
+            entry.url,
+            entry.operation_name,
+            entry.query,
+            entry.variables,
+            entry.headers,
+            entry.status,
+            entry.latency_ms,
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn load_recent(connection: &Connection, limit: i64) -> Result<Vec<HistoryEntry>, Error> {
+    let mut statement = connection.prepare(
+        "SELECT timestamp, url, operation_name, query, variables, headers, status, latency_ms
+         FROM history ORDER BY id DESC LIMIT ?1",
+    )?;
+
+    let rows = statement.query_map(params![limit], |row| {
+        Ok(HistoryEntry {
+            timestamp: row.get(0)?,
+            url: row.get(1)?,
+            operation_name: row.get(2)?,
+            query: row.get(3)?,
+            variables: row.get(4)?,
+            headers: row.get(5)?,
+            status: row.get(6)?,
+            latency_ms: row.get(7)?,
+        })
+    })?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(Error::from)
+}
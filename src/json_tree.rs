@@ -0,0 +1,125 @@
+use crate::tree::CollapsibleItem;
+use serde::Serialize;
+
+/// One line of a flattened, collapsible view over a JSON value. Uses the
+/// same indent/visible model as `collections::Collection` so folding a large
+/// array or object works the same way the collections sidebar does.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonTreeItem {
+    pub label: String,
+    pub indent: u8,
+    pub collapsible: bool,
+    pub collapsed: bool,
+    pub visible: bool,
+}
+
+impl CollapsibleItem for JsonTreeItem {
+    fn indent(&self) -> u8 {
+        self.indent
+    }
+
+    fn collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed = collapsed;
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn is_collapsible(&self) -> bool {
+        self.collapsible
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonTree {
+    pub items: Vec<JsonTreeItem>,
+}
+
+impl JsonTree {
+    pub fn from_value<T: Serialize>(value: &T) -> JsonTree {
+        let json = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
+
+        let mut items = Vec::new();
+
+        push_value(&mut items, None, &json, 0);
+
+        JsonTree { items }
+    }
+
+    pub fn visible_indices(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.visible)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Toggles the node at `index`. See `tree::toggle_collapse` for the
+    /// cascade rules.
+    pub fn toggle_collapse(&mut self, index: usize) {
+        crate::tree::toggle_collapse(&mut self.items, index);
+    }
+}
+
+fn push_value(
+    items: &mut Vec<JsonTreeItem>,
+    key: Option<String>,
+    value: &serde_json::Value,
+    indent: u8,
+) {
+    let prefix = key.map(|key| format!("{}: ", key)).unwrap_or_default();
+
+    match value {
+        serde_json::Value::Object(map) => {
+            items.push(JsonTreeItem {
+                label: format!(
+                    "{}{{...}} ({} field{})",
+                    prefix,
+                    map.len(),
+                    if map.len() == 1 { "" } else { "s" }
+                ),
+                indent,
+                collapsible: !map.is_empty(),
+                collapsed: false,
+                visible: true,
+            });
+
+            for (key, value) in map {
+                push_value(items, Some(key.clone()), value, indent + 1);
+            }
+        }
+        serde_json::Value::Array(list) => {
+            items.push(JsonTreeItem {
+                label: format!(
+                    "{}[...] ({} item{})",
+                    prefix,
+                    list.len(),
+                    if list.len() == 1 { "" } else { "s" }
+                ),
+                indent,
+                collapsible: !list.is_empty(),
+                collapsed: false,
+                visible: true,
+            });
+
+            for (index, value) in list.iter().enumerate() {
+                push_value(items, Some(index.to_string()), value, indent + 1);
+            }
+        }
+        other => {
+            items.push(JsonTreeItem {
+                label: format!("{}{}", prefix, other),
+                indent,
+                collapsible: false,
+                collapsed: false,
+                visible: true,
+            });
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crossterm::{
 };
 
 use std::io;
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 use tui::{
@@ -12,18 +12,68 @@ use tui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::*,
     text::{Span, Spans, Text},
-    widgets::{Block, BorderType, Borders, Paragraph, Tabs},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Tabs},
     Terminal,
 };
 
+mod collections;
+mod error;
 mod graphql;
+mod history;
+mod json_tree;
 mod redux;
+mod tree;
 
-const QUERY: &str = "query character { id, name, status }";
+fn operation_names(query: &str) -> Vec<String> {
+    graphql_parser::query::parse_query::<&str>(query)
+        .map(|document| {
+            document
+                .definitions
+                .into_iter()
+                .filter_map(|definition| match definition {
+                    graphql_parser::query::Definition::Operation(operation) => match operation {
+                        graphql_parser::query::OperationDefinition::Query(query) => {
+                            query.name.map(|name| name.to_string())
+                        }
+                        graphql_parser::query::OperationDefinition::Mutation(mutation) => {
+                            mutation.name.map(|name| name.to_string())
+                        }
+                        graphql_parser::query::OperationDefinition::Subscription(subscription) => {
+                            subscription.name.map(|name| name.to_string())
+                        }
+                        graphql_parser::query::OperationDefinition::SelectionSet(_) => None,
+                    },
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
 enum Event<I> {
     Input(I),
     Tick,
+    Response(Result<(u16, graphql::GraphQLResponse<graphql::CharacterDataField>), error::Error>),
+    HistoryRecorded(history::HistoryEntry),
+    Error(String),
+}
+
+/// Sends an event to the render loop, translating a dropped receiver into
+/// the typed `Error::ChannelSend` instead of a bare `mpsc::SendError`.
+fn send_event<I>(tx: &mpsc::Sender<Event<I>>, event: Event<I>) -> Result<(), error::Error> {
+    tx.send(event).map_err(|_| error::Error::ChannelSend)
+}
+
+/// Locks the shared history connection, recovering from a poisoned lock
+/// instead of panicking. A panic elsewhere while holding the lock doesn't
+/// leave the connection itself broken, so there's nothing worth unwinding
+/// over here.
+fn lock_history(
+    connection: &Mutex<rusqlite::Connection>,
+) -> std::sync::MutexGuard<'_, rusqlite::Connection> {
+    connection
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -36,6 +86,7 @@ enum ActiveMainPane {
 enum TabMenuItem {
     Execution(ActiveMainPane),
     Collection,
+    History,
 }
 
 impl From<ActiveMainPane> for usize {
@@ -47,8 +98,9 @@ impl From<ActiveMainPane> for usize {
 impl From<TabMenuItem> for usize {
     fn from(input: TabMenuItem) -> usize {
         match input {
-            TabMenuItem::Execution(_) => 1,
             TabMenuItem::Collection => 0,
+            TabMenuItem::Execution(_) => 1,
+            TabMenuItem::History => 2,
         }
     }
 }
@@ -63,49 +115,164 @@ fn get_color(menu_item: TabMenuItem, pane: ActiveMainPane) -> tui::style::Color
 
 #[derive(PartialEq, Eq, Clone, Debug, Copy)]
 enum ActiveWindow {
-    Menu,
-    URL,
+    Url,
+    Query,
+    Variables,
+    Headers,
     Main,
-    Footer,
+}
+
+/// Parses the `Headers` pane's `Key: Value` lines into header pairs. A line
+/// whose key is `Authorization` and whose value is `Bearer <token>` is pulled
+/// out separately so it can be applied through `RequestContext::auth`.
+fn parse_headers(input: &str) -> (Option<String>, Vec<(String, String)>) {
+    let mut auth_token = None;
+    let mut headers = Vec::new();
+
+    for line in input.lines() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            let name = name.trim();
+            let value = value.trim();
+
+            if let Some(token) = value
+                .strip_prefix("Bearer ")
+                .filter(|_| name.eq_ignore_ascii_case("authorization"))
+            {
+                auth_token = Some(token.to_string());
+                continue;
+            }
+
+            headers.push((name.to_string(), value.to_string()));
+        }
+    }
+
+    (auth_token, headers)
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, Copy)]
 enum Mode {
     Normal,
     Insert,
+    Search,
 }
 
-#[derive(Clone)]
 enum Action {
-    Noop,
     ChangeURI(String),
     ChangeMode(Mode),
     SetFirstRender,
+    SetLoading(bool),
+    SetResponse(Result<(u16, graphql::GraphQLResponse<graphql::CharacterDataField>), error::Error>),
+    SetActiveWindow(ActiveWindow),
+    ChangeQuery(String),
+    ChangeVariables(String),
+    ChangeHeaders(String),
+    SetOperationName(Option<String>),
+    SetCollections(collections::Collection),
+    SelectCollectionItem(usize),
+    LoadRequest(collections::SavedRequest),
+    SetError(String),
+    SetHistory(Vec<history::HistoryEntry>),
+    PushHistoryEntry(history::HistoryEntry),
+    SelectHistoryItem(usize),
+    SetResultScroll(u16),
+    ToggleResponseCollapse(usize),
+    SetSearchQuery(String),
+    JumpSearchMatch,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 struct AppState {
     url_input: String,
+    query_input: String,
+    variables_input: String,
+    variables_valid: bool,
+    headers_input: String,
+    operation_name: Option<String>,
     active_window: ActiveWindow,
     mode: Mode,
     is_first_render: bool,
+    is_loading: bool,
+    response: Option<graphql::GraphQLResponse<graphql::CharacterDataField>>,
+    collections: collections::Collection,
+    collections_selected: usize,
+    last_error: Option<String>,
+    history: Vec<history::HistoryEntry>,
+    history_selected: usize,
+    response_tree: json_tree::JsonTree,
+    result_scroll: u16,
+    search_query: String,
+    search_matches: Vec<usize>,
+    search_match_cursor: usize,
 }
 
 impl Default for AppState {
     fn default() -> Self {
         AppState {
             url_input: "https://rickandmortyapi.com/graphql".to_string(),
-            active_window: ActiveWindow::URL,
+            query_input: "query character { id, name, status }".to_string(),
+            variables_input: "{}".to_string(),
+            variables_valid: true,
+            headers_input: String::new(),
+            operation_name: None,
+            active_window: ActiveWindow::Url,
             mode: Mode::Insert,
             is_first_render: true,
+            is_loading: false,
+            response: None,
+            collections: collections::Collection::default(),
+            collections_selected: 0,
+            last_error: None,
+            history: Vec::new(),
+            history_selected: 0,
+            response_tree: json_tree::JsonTree::default(),
+            result_scroll: 0,
+            search_query: String::new(),
+            search_matches: Vec::new(),
+            search_match_cursor: 0,
         }
     }
 }
 
+/// Recomputes which visible lines of `tree` match `query` (case-insensitive
+/// substring match against the rendered label).
+fn search_matches(tree: &json_tree::JsonTree, query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let query = query.to_lowercase();
+
+    tree.visible_indices()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, item_index)| {
+            tree.items[*item_index]
+                .label
+                .to_lowercase()
+                .contains(&query)
+        })
+        .map(|(position, _)| position)
+        .collect()
+}
+
 fn get_position_x(input: String) -> u16 {
     input.chars().count().try_into().unwrap_or(0) + 2
 }
 
+struct RawModeGuard;
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let stdout = io::stdout();
@@ -114,10 +281,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut terminal = Terminal::new(crossterm_backend)?;
 
+    // Opened once here and shared by every history read/write so requests
+    // don't each reopen the database and re-run the schema migration.
+    let history_connection = Arc::new(Mutex::new(history::open_connection()?));
+
     let mut store = redux::Store::new(
         AppState::default(),
         Box::new(|mut state: AppState, action: Action| match action {
-            Action::Noop => state,
             Action::ChangeURI(uri) => {
                 state.url_input = uri;
 
@@ -131,17 +301,184 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             Action::SetFirstRender => {
                 state.is_first_render = false;
 
+                state
+            }
+            Action::SetLoading(is_loading) => {
+                state.is_loading = is_loading;
+
+                if is_loading {
+                    state.last_error = None;
+                }
+
+                state
+            }
+            Action::SetResponse(result) => {
+                state.is_loading = false;
+
+                match result {
+                    Ok((_, response)) => {
+                        state.response_tree = json_tree::JsonTree::from_value(&response);
+                        state.response = Some(response);
+                        state.last_error = None;
+                        state.result_scroll = 0;
+                        state.search_query = String::new();
+                        state.search_matches = Vec::new();
+                        state.search_match_cursor = 0;
+                    }
+                    Err(error) => {
+                        state.last_error = Some(error.to_string());
+                    }
+                }
+
+                state
+            }
+            Action::SetActiveWindow(active_window) => {
+                state.active_window = active_window;
+
+                state
+            }
+            Action::ChangeQuery(query) => {
+                state.query_input = query;
+
+                state
+            }
+            Action::ChangeVariables(variables) => {
+                state.variables_valid =
+                    serde_json::from_str::<serde_json::Value>(&variables).is_ok();
+                state.variables_input = variables;
+
+                state
+            }
+            Action::ChangeHeaders(headers) => {
+                state.headers_input = headers;
+
+                state
+            }
+            Action::SetOperationName(operation_name) => {
+                state.operation_name = operation_name;
+
+                state
+            }
+            Action::SetCollections(collections) => {
+                state.collections = collections;
+
+                state
+            }
+            Action::SelectCollectionItem(index) => {
+                state.collections_selected = index;
+
+                state
+            }
+            Action::LoadRequest(request) => {
+                state.url_input = request.url;
+                state.query_input = request.query;
+                state.variables_valid =
+                    serde_json::from_str::<serde_json::Value>(&request.variables).is_ok();
+                state.variables_input = request.variables;
+                state.headers_input = request
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                state.operation_name = None;
+
+                state
+            }
+            Action::SetError(message) => {
+                state.last_error = Some(message);
+
+                state
+            }
+            Action::SetHistory(history) => {
+                state.history = history;
+
+                state
+            }
+            Action::PushHistoryEntry(entry) => {
+                state.history.insert(0, entry);
+
+                state
+            }
+            Action::SelectHistoryItem(index) => {
+                state.history_selected = index;
+
+                state
+            }
+            Action::SetResultScroll(scroll) => {
+                let max_scroll = state
+                    .response_tree
+                    .visible_indices()
+                    .len()
+                    .saturating_sub(1) as u16;
+
+                state.result_scroll = scroll.min(max_scroll);
+
+                state
+            }
+            Action::ToggleResponseCollapse(index) => {
+                state.response_tree.toggle_collapse(index);
+
+                let max_scroll = state
+                    .response_tree
+                    .visible_indices()
+                    .len()
+                    .saturating_sub(1) as u16;
+
+                state.result_scroll = state.result_scroll.min(max_scroll);
+
+                state.search_matches = search_matches(&state.response_tree, &state.search_query);
+                state.search_match_cursor = state
+                    .search_match_cursor
+                    .min(state.search_matches.len().saturating_sub(1));
+
+                state
+            }
+            Action::SetSearchQuery(query) => {
+                state.search_matches = search_matches(&state.response_tree, &query);
+                state.search_match_cursor = 0;
+                state.search_query = query;
+
+                state
+            }
+            Action::JumpSearchMatch => {
+                if !state.search_matches.is_empty() {
+                    let max_scroll = state
+                        .response_tree
+                        .visible_indices()
+                        .len()
+                        .saturating_sub(1) as u16;
+
+                    state.result_scroll =
+                        (state.search_matches[state.search_match_cursor] as u16).min(max_scroll);
+                    state.search_match_cursor =
+                        (state.search_match_cursor + 1) % state.search_matches.len();
+                }
+
                 state
             }
         }),
     );
 
+    store.dispatch(Action::SetCollections(collections::Collection::load()));
+    store.dispatch(Action::SetHistory(
+        history::load_recent(&lock_history(&history_connection), 200).unwrap_or_default(),
+    ));
+
     enable_raw_mode().expect("can run in raw mode");
 
+    // Restores the terminal on every exit path, including an early return via `?`,
+    // so a rendering or I/O error never leaves the user's shell in raw mode.
+    let _raw_mode_guard = RawModeGuard;
+
     let (tx, rx) = mpsc::channel();
 
     let tick_rate = Duration::from_millis(200);
 
+    // Clone the sender for the input thread so `tx` itself stays available
+    // for the request worker tasks spawned later.
+    let input_tx = tx.clone();
+
     // "Move" moves the ownership to the thread.
     // This is listening for inputs in  a separate thread, not blocking the main rendering thread.
     thread::spawn(move || {
@@ -152,16 +489,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .checked_sub(last_tick.elapsed())
                 .unwrap_or_else(|| Duration::from_secs(0));
 
-            if event::poll(timeout).expect("poll works") {
-                if let CEvent::Key(key) = event::read().expect("can read events") {
-                    tx.send(Event::Input(key)).expect("can send events");
+            // A poll/read failure is surfaced as `Action::SetError` through the
+            // event channel rather than unwinding this thread, which would
+            // otherwise tear down the terminal without restoring it.
+            let input_event = match event::poll(timeout) {
+                Ok(true) => match event::read() {
+                    Ok(CEvent::Key(key)) => Some(Event::Input(key)),
+                    Ok(_) => None,
+                    Err(error) => Some(Event::Error(error.to_string())),
+                },
+                Ok(false) => None,
+                Err(error) => Some(Event::Error(error.to_string())),
+            };
+
+            if let Some(event) = input_event {
+                if send_event(&input_tx, event).is_err() {
+                    break;
                 }
             }
 
             if last_tick.elapsed() >= tick_rate {
-                if let Ok(_) = tx.send(Event::Tick) {
-                    last_tick = Instant::now();
+                if send_event(&input_tx, Event::Tick).is_err() {
+                    break;
                 }
+                last_tick = Instant::now();
             }
         }
     });
@@ -170,30 +521,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut active_menu_item = TabMenuItem::Execution(ActiveMainPane::Left);
 
-    let mut resp: Option<graphql::GraphQLResponse<graphql::CharacterDataField>> = None;
-
     loop {
-        let payload_to_display = match &resp {
-            Some(payload) => serde_json::to_string_pretty(payload)?,
-            None => " nothing.".to_string(),
-        };
-
-        let document = graphql_parser::query::parse_query::<&str>(QUERY)?;
-
-        let formatted_query = format!("{}", document);
+        let formatted_query =
+            match graphql_parser::query::parse_query::<&str>(&store.get_state().query_input) {
+                Ok(document) => format!("{}", document),
+                Err(_) => store.get_state().query_input,
+            };
 
         terminal.draw(|rect| {
             let main = Block::default().title("Main").borders(Borders::ALL);
             let endpoint_url = Block::default()
                 .title("URL")
-                .border_style(if store.get_state().active_window == ActiveWindow::URL {
+                .border_style(if store.get_state().active_window == ActiveWindow::Url {
                     Style::fg(Style::default(), Color::Red)
                 } else {
                     Style::default()
                 })
                 .borders(Borders::ALL);
 
-            let menu_titles = vec!["collections", "execute"];
+            let menu_titles = ["collections", "execute", "history"];
 
             let main_layout = Layout::default()
                 .direction(Direction::Vertical)
@@ -234,8 +580,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             rect.render_widget(tabs, main_layout[0]);
 
-            let footer = Paragraph::new("Footer message")
-                .style(Style::default().fg(Color::LightCyan))
+            let operation_label = match &store.get_state().operation_name {
+                Some(name) => name.clone(),
+                None => "(default)".to_string(),
+            };
+
+            let footer_text = if store.get_state().mode == Mode::Search {
+                format!("search: {}_", store.get_state().search_query)
+            } else if let Some(error) = &store.get_state().last_error {
+                error.clone()
+            } else if store.get_state().is_loading {
+                "Loading...".to_string()
+            } else if !store.get_state().search_matches.is_empty() {
+                format!(
+                    "operation: {} | {} match(es) | n: next match",
+                    operation_label,
+                    store.get_state().search_matches.len()
+                )
+            } else {
+                format!("operation: {} | o: cycle operation", operation_label)
+            };
+
+            let footer_color = if store.get_state().last_error.is_some() {
+                Color::Red
+            } else {
+                Color::LightCyan
+            };
+
+            let footer = Paragraph::new(footer_text)
+                .style(Style::default().fg(footer_color))
                 .alignment(Alignment::Center)
                 .block(
                     Block::default()
@@ -254,17 +627,150 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             let query_content = Paragraph::new(Text::raw(formatted_query)).block(main_left);
 
+            let variables_block = Block::default()
+                .title("Variables")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if !store.get_state().variables_valid {
+                    Color::Red
+                } else if store.get_state().active_window == ActiveWindow::Variables {
+                    Color::Magenta
+                } else {
+                    Color::White
+                }));
+
+            let variables_content =
+                Paragraph::new(Text::raw(store.get_state().variables_input)).block(variables_block);
+
+            let headers_block = Block::default()
+                .title("Headers (Key: Value per line, Authorization: Bearer <token> for auth)")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(
+                    if store.get_state().active_window == ActiveWindow::Headers {
+                        Color::Magenta
+                    } else {
+                        Color::White
+                    },
+                ));
+
+            let headers_content =
+                Paragraph::new(Text::raw(store.get_state().headers_input)).block(headers_block);
+
             let url_text = Paragraph::new(store.get_state().url_input).block(endpoint_url);
 
+            let collections_list_items: Vec<ListItem> = store
+                .get_state()
+                .collections
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.visible)
+                .map(|(index, item)| {
+                    let indent = "  ".repeat(item.indent as usize);
+                    let label = match &item.node {
+                        collections::TreeNode::Folder { name } => {
+                            format!(
+                                "{}{} {}",
+                                indent,
+                                if item.collapsed { "▸" } else { "▾" },
+                                name
+                            )
+                        }
+                        collections::TreeNode::Request { name, .. } => {
+                            format!("{}  {}", indent, name)
+                        }
+                    };
+
+                    let style = if index == store.get_state().collections_selected {
+                        Style::default().fg(Color::Black).bg(Color::Yellow)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+
+                    ListItem::new(label).style(style)
+                })
+                .collect();
+
+            let collections_list = List::new(collections_list_items).block(
+                Block::default()
+                    .title("Collections (Enter/Space: expand or load, j/k: move, s: save)")
+                    .borders(Borders::ALL),
+            );
+
+            let history_list_items: Vec<ListItem> = store
+                .get_state()
+                .history
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    let label = format!(
+                        "{} | {} | {} | {}ms",
+                        entry.status,
+                        entry.operation_name.as_deref().unwrap_or("(default)"),
+                        entry.url,
+                        entry.latency_ms
+                    );
+
+                    let style = if index == store.get_state().history_selected {
+                        Style::default().fg(Color::Black).bg(Color::Yellow)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+
+                    ListItem::new(label).style(style)
+                })
+                .collect();
+
+            let history_list = List::new(history_list_items).block(
+                Block::default()
+                    .title("History (j/k: move, Enter/Space: load)")
+                    .borders(Borders::ALL),
+            );
+
             let main_right = Block::default()
-                .title("MainRight")
+                .title("MainRight (/: search, n: next match, j/k: scroll, Enter/Space: fold)")
                 .borders(Borders::ALL)
-                .border_style(
-                    Style::default().fg(get_color(active_menu_item, ActiveMainPane::Right)),
-                );
+                .border_style(Style::default().fg(
+                    if store.get_state().active_window == ActiveWindow::Main {
+                        Color::Magenta
+                    } else {
+                        get_color(active_menu_item, ActiveMainPane::Right)
+                    },
+                ));
+
+            let result_state = store.get_state();
+            let result_visible = result_state.response_tree.visible_indices();
+
+            let result_lines: Vec<Spans> = if result_visible.is_empty() {
+                [Spans::from(Span::raw(" nothing."))].into()
+            } else {
+                let query = result_state.search_query.to_lowercase();
+
+                result_visible
+                    .iter()
+                    .enumerate()
+                    .map(|(position, &item_index)| {
+                        let item = &result_state.response_tree.items[item_index];
+                        let indent = "  ".repeat(item.indent as usize);
+                        let text = format!("{}{}", indent, item.label);
 
-            let result_content = Paragraph::new(Text::raw(payload_to_display))
-                .style(Style::default().fg(Color::LightCyan))
+                        let is_match = !query.is_empty() && text.to_lowercase().contains(&query);
+                        let is_selected = position as u16 == result_state.result_scroll;
+
+                        let style = if is_match {
+                            Style::default().fg(Color::Black).bg(Color::Yellow)
+                        } else if is_selected {
+                            Style::default().fg(Color::Black).bg(Color::White)
+                        } else {
+                            Style::default().fg(Color::LightCyan)
+                        };
+
+                        Spans::from(Span::styled(text, style))
+                    })
+                    .collect()
+            };
+
+            let result_content = Paragraph::new(Text::from(result_lines))
+                .scroll((result_state.result_scroll, 0))
                 .block(main_right);
 
             let pains_inside_main = Layout::default()
@@ -273,15 +779,41 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
                 .split(main_layout[2]);
 
+            let left_pane = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Percentage(50),
+                        Constraint::Percentage(25),
+                        Constraint::Percentage(25),
+                    ]
+                    .as_ref(),
+                )
+                .split(pains_inside_main[0]);
+
             rect.render_widget(url_text, main_layout[1]);
-            rect.render_widget(main, main_layout[2]);
             rect.render_widget(footer, main_layout[3]);
 
-            rect.render_widget(query_content, pains_inside_main[0]);
-            rect.render_widget(result_content, pains_inside_main[1]);
+            match active_menu_item {
+                TabMenuItem::Collection => {
+                    rect.render_widget(collections_list, main_layout[2]);
+                }
+                TabMenuItem::History => {
+                    rect.render_widget(history_list, main_layout[2]);
+                }
+                TabMenuItem::Execution(_) => {
+                    rect.render_widget(main, main_layout[2]);
+                    rect.render_widget(query_content, left_pane[0]);
+                    rect.render_widget(variables_content, left_pane[1]);
+                    rect.render_widget(headers_content, left_pane[2]);
+                    rect.render_widget(result_content, pains_inside_main[1]);
+                }
+            }
         })?;
 
-        if store.get_state().mode == Mode::Insert {
+        if store.get_state().mode == Mode::Insert
+            && store.get_state().active_window == ActiveWindow::Url
+        {
             let position_x = get_position_x(store.get_state().url_input);
 
             terminal.set_cursor(position_x, 6)?;
@@ -298,12 +830,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             store.dispatch(Action::SetFirstRender);
         }
 
-        match store.get_state().mode {
-            Mode::Normal => match rx.recv()? {
-                Event::Input(event) => match event.code {
+        match rx.recv()? {
+            Event::Response(result) => {
+                store.dispatch(Action::SetResponse(result));
+            }
+            Event::HistoryRecorded(entry) => {
+                store.dispatch(Action::PushHistoryEntry(entry));
+            }
+            Event::Error(message) => {
+                store.dispatch(Action::SetError(message));
+            }
+            Event::Tick => {}
+            Event::Input(event) => match store.get_state().mode {
+                Mode::Normal => match event.code {
                     KeyCode::Char('q') => {
-                        disable_raw_mode()?;
-
                         break;
                     }
                     KeyCode::Char('c') => {
@@ -315,37 +855,363 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     KeyCode::Char('e') => {
                         active_menu_item = TabMenuItem::Execution(ActiveMainPane::Left)
                     }
-                    KeyCode::Char(' ') => {
-                        resp = Some(graphql::perform_graphql().await?);
-                        ()
+                    KeyCode::Char('h') => {
+                        active_menu_item = TabMenuItem::History;
+                    }
+                    KeyCode::Tab => {
+                        let next_window = match store.get_state().active_window {
+                            ActiveWindow::Url => ActiveWindow::Query,
+                            ActiveWindow::Query => ActiveWindow::Variables,
+                            ActiveWindow::Variables => ActiveWindow::Headers,
+                            ActiveWindow::Headers => ActiveWindow::Main,
+                            ActiveWindow::Main => ActiveWindow::Url,
+                        };
+
+                        store.dispatch(Action::SetActiveWindow(next_window));
+                    }
+                    KeyCode::Char('o') => {
+                        let names = operation_names(&store.get_state().query_input);
+
+                        if !names.is_empty() {
+                            let next_name = match &store.get_state().operation_name {
+                                Some(current) => names
+                                    .iter()
+                                    .position(|name| name == current)
+                                    .map(|index| names[(index + 1) % names.len()].clone())
+                                    .unwrap_or_else(|| names[0].clone()),
+                                None => names[0].clone(),
+                            };
+
+                            store.dispatch(Action::SetOperationName(Some(next_name)));
+                        }
+                    }
+                    KeyCode::Char('j') if matches!(active_menu_item, TabMenuItem::Collection) => {
+                        let state = store.get_state();
+                        let visible = state.collections.visible_indices();
+
+                        if let Some(position) = visible
+                            .iter()
+                            .position(|index| *index == state.collections_selected)
+                        {
+                            if let Some(next) = visible.get(position + 1) {
+                                store.dispatch(Action::SelectCollectionItem(*next));
+                            }
+                        } else if let Some(first) = visible.first() {
+                            store.dispatch(Action::SelectCollectionItem(*first));
+                        }
+                    }
+                    KeyCode::Char('k') if matches!(active_menu_item, TabMenuItem::Collection) => {
+                        let state = store.get_state();
+                        let visible = state.collections.visible_indices();
+
+                        if let Some(position) = visible
+                            .iter()
+                            .position(|index| *index == state.collections_selected)
+                        {
+                            if position > 0 {
+                                store.dispatch(Action::SelectCollectionItem(visible[position - 1]));
+                            }
+                        } else if let Some(first) = visible.first() {
+                            store.dispatch(Action::SelectCollectionItem(*first));
+                        }
+                    }
+                    KeyCode::Char('s') if matches!(active_menu_item, TabMenuItem::Collection) => {
+                        if let Err(error) = store.get_state().collections.save() {
+                            store.dispatch(Action::SetError(error.to_string()));
+                        }
+                    }
+                    KeyCode::Char('j') if matches!(active_menu_item, TabMenuItem::History) => {
+                        let state = store.get_state();
+
+                        if state.history_selected + 1 < state.history.len() {
+                            store.dispatch(Action::SelectHistoryItem(state.history_selected + 1));
+                        }
+                    }
+                    KeyCode::Char('k') if matches!(active_menu_item, TabMenuItem::History) => {
+                        let state = store.get_state();
+
+                        if state.history_selected > 0 {
+                            store.dispatch(Action::SelectHistoryItem(state.history_selected - 1));
+                        }
+                    }
+                    KeyCode::Enter | KeyCode::Char(' ')
+                        if matches!(active_menu_item, TabMenuItem::History) =>
+                    {
+                        let state = store.get_state();
+
+                        if let Some(entry) = state.history.get(state.history_selected).cloned() {
+                            store.dispatch(Action::LoadRequest(collections::SavedRequest {
+                                url: entry.url,
+                                query: entry.query,
+                                variables: entry.variables,
+                                headers: Vec::new(),
+                            }));
+                            // The history entry keeps the headers pane's raw
+                            // text rather than parsed pairs, so restore the
+                            // headers pane straight from the recorded text.
+                            store.dispatch(Action::ChangeHeaders(entry.headers));
+                            store.dispatch(Action::SetOperationName(entry.operation_name));
+                        }
+                    }
+                    KeyCode::Char('/')
+                        if matches!(active_menu_item, TabMenuItem::Execution(_))
+                            && store.get_state().active_window == ActiveWindow::Main =>
+                    {
+                        store.dispatch(Action::SetSearchQuery(String::new()));
+                        store.dispatch(Action::ChangeMode(Mode::Search));
+                    }
+                    KeyCode::Char('n')
+                        if matches!(active_menu_item, TabMenuItem::Execution(_))
+                            && store.get_state().active_window == ActiveWindow::Main =>
+                    {
+                        store.dispatch(Action::JumpSearchMatch);
+                    }
+                    KeyCode::Char('j')
+                        if matches!(active_menu_item, TabMenuItem::Execution(_))
+                            && store.get_state().active_window == ActiveWindow::Main =>
+                    {
+                        let scroll = store.get_state().result_scroll;
+
+                        store.dispatch(Action::SetResultScroll(scroll + 1));
+                    }
+                    KeyCode::Char('k')
+                        if matches!(active_menu_item, TabMenuItem::Execution(_))
+                            && store.get_state().active_window == ActiveWindow::Main =>
+                    {
+                        let scroll = store.get_state().result_scroll;
+
+                        store.dispatch(Action::SetResultScroll(scroll.saturating_sub(1)));
+                    }
+                    KeyCode::PageDown
+                        if matches!(active_menu_item, TabMenuItem::Execution(_))
+                            && store.get_state().active_window == ActiveWindow::Main =>
+                    {
+                        let scroll = store.get_state().result_scroll;
+
+                        store.dispatch(Action::SetResultScroll(scroll + 10));
+                    }
+                    KeyCode::PageUp
+                        if matches!(active_menu_item, TabMenuItem::Execution(_))
+                            && store.get_state().active_window == ActiveWindow::Main =>
+                    {
+                        let scroll = store.get_state().result_scroll;
+
+                        store.dispatch(Action::SetResultScroll(scroll.saturating_sub(10)));
+                    }
+                    KeyCode::Enter | KeyCode::Char(' ')
+                        if matches!(active_menu_item, TabMenuItem::Execution(_))
+                            && store.get_state().active_window == ActiveWindow::Main =>
+                    {
+                        let state = store.get_state();
+                        let visible = state.response_tree.visible_indices();
+
+                        if let Some(item_index) = visible.get(state.result_scroll as usize) {
+                            store.dispatch(Action::ToggleResponseCollapse(*item_index));
+                        }
+                    }
+                    KeyCode::Enter | KeyCode::Char(' ')
+                        if matches!(active_menu_item, TabMenuItem::Collection) =>
+                    {
+                        let state = store.get_state();
+                        let selected = state.collections_selected;
+
+                        if let Some(item) = state.collections.items.get(selected).cloned() {
+                            match item.node {
+                                collections::TreeNode::Folder { .. } => {
+                                    let mut collections = state.collections;
+
+                                    collections.toggle_collapse(selected);
+
+                                    store.dispatch(Action::SetCollections(collections));
+                                }
+                                collections::TreeNode::Request { request, .. } => {
+                                    store.dispatch(Action::LoadRequest(request));
+                                }
+                            }
+                        }
+                    }
+                    KeyCode::Char(' ') if !store.get_state().is_loading => {
+                        let state = store.get_state();
+
+                        if let Err(parse_error) =
+                            graphql_parser::query::parse_query::<&str>(&state.query_input)
+                        {
+                            store.dispatch(Action::SetResponse(Err(error::Error::Parse(
+                                parse_error.to_string(),
+                            ))));
+                            continue;
+                        }
+
+                        let variables: serde_json::Value =
+                            serde_json::from_str(&state.variables_input)
+                                .unwrap_or_else(|_| serde_json::json!({}));
+
+                        let query = state.query_input;
+                        let operation_name = state.operation_name;
+
+                        let (auth_token, headers) = parse_headers(&state.headers_input);
+                        let mut context = graphql::RequestContext {
+                            url: state.url_input,
+                            ..Default::default()
+                        };
+                        context.headers = headers;
+                        if let Some(token) = auth_token {
+                            context = context.auth(token);
+                        }
+
+                        store.dispatch(Action::SetLoading(true));
+
+                        let tx = tx.clone();
+                        let history_connection = history_connection.clone();
+                        let history_url = context.url.clone();
+                        let history_query = query.clone();
+                        let history_variables = state.variables_input.clone();
+                        let history_operation_name = operation_name.clone();
+                        let history_headers = state.headers_input.clone();
+
+                        // Hand the request off to the tokio task pool so a slow
+                        // endpoint can't freeze the render loop; the result comes
+                        // back through the same channel as input/tick events. The
+                        // history entry is recorded from this same worker so the
+                        // render loop never blocks on the database either.
+                        tokio::spawn(async move {
+                            let started_at = Instant::now();
+
+                            let result = graphql::perform_graphql(
+                                &context,
+                                &query,
+                                variables,
+                                operation_name.as_deref(),
+                            )
+                            .await;
+
+                            let status = match &result {
+                                Ok((status, _)) => status.to_string(),
+                                Err(error) => error.to_string(),
+                            };
+
+                            let entry = history::HistoryEntry {
+                                timestamp: history::now_unix(),
+                                url: history_url,
+                                operation_name: history_operation_name,
+                                query: history_query,
+                                variables: history_variables,
+                                headers: history_headers,
+                                status,
+                                latency_ms: started_at.elapsed().as_millis() as i64,
+                            };
+
+                            let recorded =
+                                history::record(&lock_history(&history_connection), &entry).is_ok();
+
+                            if recorded {
+                                let _ = send_event(&tx, Event::HistoryRecorded(entry));
+                            }
+
+                            let _ = send_event(&tx, Event::Response(result));
+                        });
                     }
                     _ => {}
                 },
-                _ => {}
-            },
-            Mode::Insert => match rx.recv()? {
-                Event::Input(event) => match event.code {
+                Mode::Insert => match event.code {
                     KeyCode::Esc => {
                         terminal.hide_cursor()?;
                         store.dispatch(Action::ChangeMode(Mode::Normal));
                     }
+                    KeyCode::Char(character) => match store.get_state().active_window {
+                        ActiveWindow::Query => {
+                            let mut query = store.get_state().query_input;
+
+                            query.push(character);
+
+                            store.dispatch(Action::ChangeQuery(query));
+                        }
+                        ActiveWindow::Variables => {
+                            let mut variables = store.get_state().variables_input;
+
+                            variables.push(character);
+
+                            store.dispatch(Action::ChangeVariables(variables));
+                        }
+                        ActiveWindow::Headers => {
+                            let mut headers = store.get_state().headers_input;
+
+                            headers.push(character);
+
+                            store.dispatch(Action::ChangeHeaders(headers));
+                        }
+                        _ => {
+                            let mut url = store.get_state().url_input;
+
+                            url.push(character);
+
+                            store.dispatch(Action::ChangeURI(url))
+                        }
+                    },
+                    KeyCode::Enter if store.get_state().active_window == ActiveWindow::Headers => {
+                        let mut headers = store.get_state().headers_input;
+
+                        headers.push('\n');
+
+                        store.dispatch(Action::ChangeHeaders(headers));
+                    }
+                    KeyCode::Backspace => match store.get_state().active_window {
+                        ActiveWindow::Query => {
+                            let mut query = store.get_state().query_input;
+
+                            query.pop();
+
+                            store.dispatch(Action::ChangeQuery(query));
+                        }
+                        ActiveWindow::Variables => {
+                            let mut variables = store.get_state().variables_input;
+
+                            variables.pop();
+
+                            store.dispatch(Action::ChangeVariables(variables));
+                        }
+                        ActiveWindow::Headers => {
+                            let mut headers = store.get_state().headers_input;
+
+                            headers.pop();
+
+                            store.dispatch(Action::ChangeHeaders(headers));
+                        }
+                        _ => {
+                            let mut url = store.get_state().url_input;
+
+                            url.pop();
+
+                            store.dispatch(Action::ChangeURI(url));
+                        }
+                    },
+                    _ => {}
+                },
+                Mode::Search => match event.code {
+                    KeyCode::Esc => {
+                        store.dispatch(Action::SetSearchQuery(String::new()));
+                        store.dispatch(Action::ChangeMode(Mode::Normal));
+                    }
+                    KeyCode::Enter => {
+                        store.dispatch(Action::ChangeMode(Mode::Normal));
+                        store.dispatch(Action::JumpSearchMatch);
+                    }
                     KeyCode::Char(character) => {
-                        let mut url = store.get_state().url_input;
+                        let mut query = store.get_state().search_query;
 
-                        url.push(character);
+                        query.push(character);
 
-                        store.dispatch(Action::ChangeURI(url))
+                        store.dispatch(Action::SetSearchQuery(query));
                     }
                     KeyCode::Backspace => {
-                        let mut url = store.get_state().url_input;
+                        let mut query = store.get_state().search_query;
 
-                        url.pop();
+                        query.pop();
 
-                        store.dispatch(Action::ChangeURI(url));
+                        store.dispatch(Action::SetSearchQuery(query));
                     }
                     _ => {}
                 },
-                _ => {}
             },
         }
     }
@@ -0,0 +1,59 @@
+/// Shared shape for a flat, pre-order listing of nested nodes where folding
+/// a node cascades `visible` over its descendants rather than requiring a
+/// recursive tree walk. Implemented by `collections::TreeItem` and
+/// `json_tree::JsonTreeItem`; the `toggle_collapse` methods of their
+/// containers, `collections::Collection` and `json_tree::JsonTree`, both
+/// delegate to `toggle_collapse` below.
+pub trait CollapsibleItem {
+    fn indent(&self) -> u8;
+    fn collapsed(&self) -> bool;
+    fn set_collapsed(&mut self, collapsed: bool);
+    fn set_visible(&mut self, visible: bool);
+
+    /// Whether this item can be toggled at all. Defaults to `true`; override
+    /// for items (e.g. an empty JSON object) that never fold.
+    fn is_collapsible(&self) -> bool {
+        true
+    }
+}
+
+/// Toggles the node at `index` and cascades `visible` onto every item more
+/// deeply indented than it, stopping at the next item at the same (or
+/// shallower) indent. Expanding stops short of any nested node that is
+/// itself still collapsed, so its children stay hidden.
+pub fn toggle_collapse<T: CollapsibleItem>(items: &mut [T], index: usize) {
+    let indent = match items.get(index) {
+        Some(item) if item.is_collapsible() => item.indent(),
+        _ => return,
+    };
+
+    let collapsed = match items.get_mut(index) {
+        Some(item) => {
+            item.set_collapsed(!item.collapsed());
+            item.collapsed()
+        }
+        None => return,
+    };
+
+    let mut hidden_below: Option<u8> = None;
+
+    for item in items.iter_mut().skip(index + 1) {
+        if item.indent() <= indent {
+            break;
+        }
+
+        if let Some(hidden_indent) = hidden_below {
+            if item.indent() > hidden_indent {
+                item.set_visible(false);
+                continue;
+            }
+            hidden_below = None;
+        }
+
+        item.set_visible(!collapsed);
+
+        if !collapsed && item.collapsed() {
+            hidden_below = Some(item.indent());
+        }
+    }
+}